@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+/// A pixel rectangle inside an atlas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        let (ax, ay) = (self.x as u64, self.y as u64);
+        let (bx, by) = (other.x as u64, other.y as u64);
+        ax < bx + other.width as u64
+            && bx < ax + self.width as u64
+            && ay < by + other.height as u64
+            && by < ay + self.height as u64
+    }
+
+    /// Normalized `[u_min, v_min, u_max, v_max]` of this rectangle inside an atlas of the given size.
+    pub fn uv(&self, atlas_width: u32, atlas_height: u32) -> [f32; 4] {
+        let w = atlas_width as f32;
+        let h = atlas_height as f32;
+        [
+            self.x as f32 / w,
+            self.y as f32 / h,
+            (self.x as f32 + self.width as f32) / w,
+            (self.y as f32 + self.height as f32) / h,
+        ]
+    }
+}
+
+/// One entry to be placed into the atlas.
+#[derive(Debug, Clone)]
+pub struct AtlasRequest<Id> {
+    pub id: Id,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Result of packing: the atlas size and where each request ended up.
+#[derive(Debug, Clone)]
+pub struct Atlas<Id> {
+    pub width: u32,
+    pub height: u32,
+    pub placements: HashMap<Id, Rect>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AtlasError {
+    EmptyRequest {
+        index: usize,
+    },
+    TooWide {
+        index: usize,
+        width: u32,
+        atlas_width: u32,
+    },
+    DuplicateId {
+        index: usize,
+    },
+    TooLarge {
+        index: usize,
+    },
+}
+
+impl fmt::Display for AtlasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AtlasError::EmptyRequest { index } => {
+                write!(f, "atlas request #{index} has a zero width or height")
+            }
+            AtlasError::TooWide {
+                index,
+                width,
+                atlas_width,
+            } => write!(
+                f,
+                "atlas request #{index} is {width}px wide but the atlas is only {atlas_width}px wide"
+            ),
+            AtlasError::DuplicateId { index } => {
+                write!(f, "atlas request #{index} reuses an id of an earlier request")
+            }
+            AtlasError::TooLarge { index } => {
+                write!(
+                    f,
+                    "atlas height exceeds {}px when placing request #{index}",
+                    u32::MAX
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AtlasError {}
+
+/// Packs all requests into an atlas of fixed width using a shelf algorithm.
+///
+/// Requests are placed tallest first, left to right; a new shelf is opened
+/// whenever the current one runs out of horizontal space.
+/// The returned atlas height is the total height of all shelves.
+pub fn pack_atlas<Id: Clone + Eq + Hash>(
+    requests: &[AtlasRequest<Id>],
+    atlas_width: u32,
+) -> Result<Atlas<Id>, AtlasError> {
+    let mut seen = HashSet::with_capacity(requests.len());
+    for (index, request) in requests.iter().enumerate() {
+        if !seen.insert(&request.id) {
+            return Err(AtlasError::DuplicateId { index });
+        }
+        if request.width == 0 || request.height == 0 {
+            return Err(AtlasError::EmptyRequest { index });
+        }
+        if request.width > atlas_width {
+            return Err(AtlasError::TooWide {
+                index,
+                width: request.width,
+                atlas_width,
+            });
+        }
+    }
+
+    let mut order: Vec<usize> = (0..requests.len()).collect();
+    order.sort_by(|&a, &b| requests[b].height.cmp(&requests[a].height));
+
+    let mut placements = HashMap::with_capacity(requests.len());
+    let mut shelf_y: u32 = 0;
+    let mut shelf_height: u32 = 0;
+    let mut cursor_x: u32 = 0;
+
+    for index in order {
+        let request = &requests[index];
+        let fits_on_shelf = cursor_x
+            .checked_add(request.width)
+            .is_some_and(|end| end <= atlas_width);
+        if !fits_on_shelf {
+            shelf_y = shelf_y
+                .checked_add(shelf_height)
+                .ok_or(AtlasError::TooLarge { index })?;
+            shelf_height = 0;
+            cursor_x = 0;
+        }
+        if shelf_y.checked_add(request.height).is_none() {
+            return Err(AtlasError::TooLarge { index });
+        }
+
+        placements.insert(
+            request.id.clone(),
+            Rect {
+                x: cursor_x,
+                y: shelf_y,
+                width: request.width,
+                height: request.height,
+            },
+        );
+
+        cursor_x += request.width;
+        shelf_height = shelf_height.max(request.height);
+    }
+
+    Ok(Atlas {
+        width: atlas_width,
+        // Every placed rect was checked to end within u32, so this cannot overflow.
+        height: shelf_y + shelf_height,
+        placements,
+    })
+}
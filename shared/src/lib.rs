@@ -1,5 +1,11 @@
 #![allow(clippy::all)]
 
+pub mod atlas;
+pub mod math;
+pub mod plot;
+pub mod scene_graph;
+pub mod tensor;
+
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,335 @@
+use std::fmt;
+
+/// Relative tolerance for area and collinearity tests.
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TriangulationError {
+    TooFewPoints { count: usize },
+    Degenerate,
+    NotSimple,
+}
+
+impl fmt::Display for TriangulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriangulationError::TooFewPoints { count } => {
+                write!(f, "a polygon needs at least 3 points, got {count}")
+            }
+            TriangulationError::Degenerate => write!(f, "polygon has zero net area"),
+            TriangulationError::NotSimple => {
+                write!(f, "polygon is self-intersecting and cannot be ear-clipped")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TriangulationError {}
+
+fn cross(o: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+/// Signed area of a polygon; positive for counter-clockwise winding.
+pub fn signed_area(points: &[[f32; 2]]) -> f32 {
+    let mut area = 0.0;
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+    area * 0.5
+}
+
+/// Area tolerance for `points`, proportional to the squared bounding-box extent
+/// so the result does not depend on the polygon's scale.
+fn area_tolerance(points: &[[f32; 2]]) -> f32 {
+    let (mut min, mut max) = (points[0], points[0]);
+    for p in points {
+        min = [min[0].min(p[0]), min[1].min(p[1])];
+        max = [max[0].max(p[0]), max[1].max(p[1])];
+    }
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    EPSILON * extent * extent
+}
+
+/// Whether the corner `a -> b -> c` is straight, i.e. the sine of its turn angle is
+/// negligible. Scaled by both edge lengths so neither the polygon's size nor its
+/// vertex density matters.
+fn is_collinear(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> bool {
+    cross(a, b, c).abs() <= EPSILON * distance(a, b) * distance(b, c)
+}
+
+fn in_triangle(p: [f32; 2], a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
+
+/// Whether segments `ab` and `cd` cross at a single point interior to both.
+/// Touching endpoints and collinear overlaps do not count.
+fn segments_cross(a: [f32; 2], b: [f32; 2], c: [f32; 2], d: [f32; 2]) -> bool {
+    let (d1, d2) = (cross(a, b, c), cross(a, b, d));
+    let (d3, d4) = (cross(c, d, a), cross(c, d, b));
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+fn has_crossing_edges(points: &[[f32; 2]]) -> bool {
+    let n = points.len();
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        // Skip the adjacent edge; it shares vertex `i + 1`.
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                // Edge `n - 1` shares vertex 0 with edge 0.
+                continue;
+            }
+            if segments_cross(a, b, points[j], points[(j + 1) % n]) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Triangulates a simple polygon via ear-clipping.
+///
+/// Points may be given in either winding order.
+/// The returned triangles index into `points` and are always counter-clockwise.
+/// Vertices lying on a straight edge are dropped rather than producing zero-area triangles.
+/// Polygons whose edges cross each other are rejected with [`TriangulationError::NotSimple`].
+pub fn triangulate_polygon(points: &[[f32; 2]]) -> Result<Vec<[usize; 3]>, TriangulationError> {
+    if points.len() < 3 {
+        return Err(TriangulationError::TooFewPoints {
+            count: points.len(),
+        });
+    }
+
+    if has_crossing_edges(points) {
+        return Err(TriangulationError::NotSimple);
+    }
+
+    let area = signed_area(points);
+    if area.abs() <= area_tolerance(points) {
+        return Err(TriangulationError::Degenerate);
+    }
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if area < 0.0 {
+        remaining.reverse();
+    }
+
+    let corner = |remaining: &[usize], i: usize| {
+        let n = remaining.len();
+        (
+            remaining[(i + n - 1) % n],
+            remaining[i % n],
+            remaining[(i + 1) % n],
+        )
+    };
+
+    // Drop straight vertices up front so they never end up as triangle corners.
+    let mut unchanged = 0;
+    let mut i = 0;
+    while remaining.len() > 3 && unchanged < remaining.len() {
+        let (prev, cur, next) = corner(&remaining, i);
+        if is_collinear(points[prev], points[cur], points[next]) {
+            remaining.remove(i % remaining.len());
+            unchanged = 0;
+        } else {
+            i = (i + 1) % remaining.len();
+            unchanged += 1;
+        }
+    }
+
+    let mut triangles = Vec::with_capacity(remaining.len() - 2);
+    let mut misses = 0;
+    let mut i = 0;
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let (prev, cur, next) = corner(&remaining, i);
+        let (a, b, c) = (points[prev], points[cur], points[next]);
+
+        // Clipping an ear can leave its neighbour on a straight line.
+        if is_collinear(a, b, c) {
+            remaining.remove(i % n);
+            misses = 0;
+            continue;
+        }
+
+        let is_ear = cross(a, b, c) > 0.0
+            && remaining
+                .iter()
+                .filter(|&&j| j != prev && j != cur && j != next)
+                .all(|&j| !in_triangle(points[j], a, b, c));
+
+        if is_ear {
+            triangles.push([prev, cur, next]);
+            remaining.remove(i % n);
+            misses = 0;
+        } else {
+            misses += 1;
+            if misses > n {
+                return Err(TriangulationError::NotSimple);
+            }
+            i = (i + 1) % n;
+        }
+    }
+
+    let (a, b, c) = (remaining[0], remaining[1], remaining[2]);
+    if !is_collinear(points[a], points[b], points[c]) {
+        triangles.push([a, b, c]);
+    }
+
+    Ok(triangles)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalsError {
+    LengthMismatch { positions: usize, normals: usize },
+    IncompleteTriangle { indices: usize },
+    IndexOutOfBounds { index: u32, vertices: usize },
+}
+
+impl fmt::Display for NormalsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalsError::LengthMismatch { positions, normals } => write!(
+                f,
+                "got {positions} positions but room for {normals} normals"
+            ),
+            NormalsError::IncompleteTriangle { indices } => {
+                write!(f, "{indices} indices do not form whole triangles")
+            }
+            NormalsError::IndexOutOfBounds { index, vertices } => {
+                write!(f, "index {index} is out of bounds for {vertices} vertices")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NormalsError {}
+
+fn sub3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn length3(v: [f32; 3]) -> f32 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+/// Computes per-vertex normals for an indexed triangle list with counter-clockwise front faces.
+///
+/// With `smooth == false` every vertex gets the normal of its face; vertices shared
+/// between faces keep the normal of the last face, so flat-shaded meshes should not
+/// share vertices. With `smooth == true` the face normals around each vertex are averaged.
+/// Zero-area triangles contribute nothing; vertices not used by any other triangle
+/// are left as `[0.0; 3]`.
+pub fn compute_normals(
+    positions: &[[f32; 3]],
+    normals: &mut [[f32; 3]],
+    indices: &[u32],
+    smooth: bool,
+) -> Result<(), NormalsError> {
+    if positions.len() != normals.len() {
+        return Err(NormalsError::LengthMismatch {
+            positions: positions.len(),
+            normals: normals.len(),
+        });
+    }
+    if !indices.len().is_multiple_of(3) {
+        return Err(NormalsError::IncompleteTriangle {
+            indices: indices.len(),
+        });
+    }
+    if let Some(&index) = indices.iter().find(|&&i| i as usize >= positions.len()) {
+        return Err(NormalsError::IndexOutOfBounds {
+            index,
+            vertices: positions.len(),
+        });
+    }
+
+    normals.fill([0.0; 3]);
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| triangle[k] as usize);
+        let ab = sub3(positions[b], positions[a]);
+        let ac = sub3(positions[c], positions[a]);
+        let face = cross3(ab, ac);
+        let length = length3(face);
+        if length <= EPSILON * length3(ab) * length3(ac) {
+            continue;
+        }
+
+        let face = face.map(|v| v / length);
+        for vertex in [a, b, c] {
+            if smooth {
+                for axis in 0..3 {
+                    normals[vertex][axis] += face[axis];
+                }
+            } else {
+                normals[vertex] = face;
+            }
+        }
+    }
+
+    if smooth {
+        for normal in normals.iter_mut() {
+            let length = length3(*normal);
+            if length > 0.0 {
+                *normal = normal.map(|v| v / length);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MorphError {
+    LengthMismatch { from: usize, to: usize },
+}
+
+impl fmt::Display for MorphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MorphError::LengthMismatch { from, to } => write!(
+                f,
+                "cannot morph between {from} and {to} vertices; both sets need the same length"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MorphError {}
+
+/// Linearly blends a vertex attribute (position, colour, normal, ...) from `from` to `to`.
+///
+/// `t = 0` yields `from` and `t = 1` yields `to` exactly; values outside 0..1 extrapolate.
+/// Blended normals are not renormalized.
+pub fn morph<const N: usize>(
+    from: &[[f32; N]],
+    to: &[[f32; N]],
+    t: f32,
+) -> Result<Vec<[f32; N]>, MorphError> {
+    if from.len() != to.len() {
+        return Err(MorphError::LengthMismatch {
+            from: from.len(),
+            to: to.len(),
+        });
+    }
+
+    Ok(from
+        .iter()
+        .zip(to)
+        .map(|(a, b)| std::array::from_fn(|i| a[i] * (1.0 - t) + b[i] * t))
+        .collect())
+}
@@ -0,0 +1,64 @@
+/// Geometry for a line plot of sampled values, in normalized device coordinates.
+///
+/// Samples are spread evenly from x = -1 to x = 1 and scaled so the smallest value
+/// maps to y = -1 and the largest to y = 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plot {
+    /// Line strips of the curve; non-finite samples split it into several strips.
+    pub strips: Vec<Vec<[f32; 2]>>,
+    /// Axis line segments: the left edge, plus the zero line when zero lies in the value range.
+    pub axes: Vec<[[f32; 2]; 2]>,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Maps `samples` to plot geometry, auto-scaling to the range of the finite samples.
+///
+/// NaN and infinite samples break the line. Runs of fewer than two finite samples
+/// cannot form a line and are left out.
+/// Returns `None` when there is no finite sample at all.
+/// If all samples are equal the curve is drawn at y = 0.
+pub fn plot_samples(samples: &[f32]) -> Option<Plot> {
+    let mut finite = samples.iter().copied().filter(|v| v.is_finite());
+    let first = finite.next()?;
+    let (min, max) = finite.fold((first, first), |(lo, hi), v| (lo.min(v), hi.max(v)));
+
+    let range = max - min;
+    let map_y = |v: f32| {
+        if range > 0.0 {
+            (v - min) / range * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    };
+    let step = if samples.len() > 1 {
+        2.0 / (samples.len() - 1) as f32
+    } else {
+        0.0
+    };
+
+    let mut strips = Vec::new();
+    let mut current = Vec::new();
+    for (i, &value) in samples.iter().enumerate() {
+        if value.is_finite() {
+            current.push([i as f32 * step - 1.0, map_y(value)]);
+        } else if !current.is_empty() {
+            strips.push(std::mem::take(&mut current));
+        }
+    }
+    strips.push(current);
+    strips.retain(|strip| strip.len() >= 2);
+
+    let mut axes = vec![[[-1.0, -1.0], [-1.0, 1.0]]];
+    if min <= 0.0 && 0.0 <= max {
+        let zero = map_y(0.0);
+        axes.push([[-1.0, zero], [1.0, zero]]);
+    }
+
+    Some(Plot {
+        strips,
+        axes,
+        min,
+        max,
+    })
+}
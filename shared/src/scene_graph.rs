@@ -0,0 +1,111 @@
+use std::ops::Mul;
+
+/// A 2D affine transform stored as a row-major 2x3 matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    pub matrix: [[f32; 3]; 2],
+}
+
+impl Transform2D {
+    pub const IDENTITY: Transform2D = Transform2D {
+        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    };
+
+    pub fn translation(x: f32, y: f32) -> Self {
+        Transform2D {
+            matrix: [[1.0, 0.0, x], [0.0, 1.0, y]],
+        }
+    }
+
+    /// Counter-clockwise rotation around the origin.
+    pub fn rotation(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Transform2D {
+            matrix: [[cos, -sin, 0.0], [sin, cos, 0.0]],
+        }
+    }
+
+    pub fn scale(x: f32, y: f32) -> Self {
+        Transform2D {
+            matrix: [[x, 0.0, 0.0], [0.0, y, 0.0]],
+        }
+    }
+
+    pub fn apply(&self, point: [f32; 2]) -> [f32; 2] {
+        let m = &self.matrix;
+        [
+            m[0][0] * point[0] + m[0][1] * point[1] + m[0][2],
+            m[1][0] * point[0] + m[1][1] * point[1] + m[1][2],
+        ]
+    }
+}
+
+/// Matrix product: `parent * child` applies `child` first, then `parent`.
+impl Mul for Transform2D {
+    type Output = Transform2D;
+
+    fn mul(self, child: Transform2D) -> Transform2D {
+        let a = &self.matrix;
+        let b = &child.matrix;
+        let mut matrix = [[0.0; 3]; 2];
+        for row in 0..2 {
+            for col in 0..3 {
+                matrix[row][col] = a[row][0] * b[0][col] + a[row][1] * b[1][col];
+            }
+            matrix[row][2] += a[row][2];
+        }
+        Transform2D { matrix }
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Transform2D::IDENTITY
+    }
+}
+
+/// A node of a 2D scene graph.
+///
+/// `geometry` is given in the node's local space; it is moved into world space by
+/// the node's transform and the transforms of all its ancestors.
+#[derive(Debug, Clone, Default)]
+pub struct SceneNode {
+    pub transform: Transform2D,
+    pub geometry: Vec<[f32; 2]>,
+    pub children: Vec<SceneNode>,
+}
+
+impl SceneNode {
+    pub fn new(transform: Transform2D) -> Self {
+        SceneNode {
+            transform,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_geometry(mut self, geometry: Vec<[f32; 2]>) -> Self {
+        self.geometry = geometry;
+        self
+    }
+
+    pub fn with_child(mut self, child: SceneNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Collects the geometry of this node and all descendants in world space,
+    /// parents before children.
+    pub fn flatten(&self) -> Vec<[f32; 2]> {
+        let mut out = Vec::new();
+        self.flatten_into(&Transform2D::IDENTITY, &mut out);
+        out
+    }
+
+    fn flatten_into(&self, parent: &Transform2D, out: &mut Vec<[f32; 2]>) {
+        let world = *parent * self.transform;
+        out.extend(self.geometry.iter().map(|&p| world.apply(p)));
+        for child in &self.children {
+            child.flatten_into(&world, out);
+        }
+    }
+}
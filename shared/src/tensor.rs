@@ -0,0 +1,105 @@
+use std::fmt;
+
+/// ImageNet per-channel mean and standard deviation, in RGB order.
+const IMAGENET_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const IMAGENET_STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// `value / 255`
+    ZeroToOne,
+    /// `value / 127.5 - 1`
+    MinusOneToOne,
+    /// `(value / 255 - mean) / std` with the ImageNet statistics for R, G and B.
+    /// Alpha, if kept, is scaled to 0..1.
+    ImageNet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorLayout {
+    /// Interleaved: all channels of a pixel are adjacent.
+    Hwc,
+    /// Planar: one full plane per channel.
+    Chw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrder {
+    Rgba,
+    Rgb,
+    Bgr,
+}
+
+impl ChannelOrder {
+    /// Indices into an RGBA pixel, in output order.
+    fn source_channels(self) -> &'static [usize] {
+        match self {
+            ChannelOrder::Rgba => &[0, 1, 2, 3],
+            ChannelOrder::Rgb => &[0, 1, 2],
+            ChannelOrder::Bgr => &[2, 1, 0],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TensorError {
+    SizeMismatch { width: u32, height: u32, len: usize },
+}
+
+impl fmt::Display for TensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TensorError::SizeMismatch { width, height, len } => write!(
+                f,
+                "a buffer of {len} bytes does not hold {width}x{height} RGBA8 pixels"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TensorError {}
+
+fn normalize(value: u8, channel: usize, normalization: Normalization) -> f32 {
+    let value = value as f32;
+    match normalization {
+        Normalization::ZeroToOne => value / 255.0,
+        Normalization::MinusOneToOne => value / 127.5 - 1.0,
+        Normalization::ImageNet if channel < 3 => {
+            (value / 255.0 - IMAGENET_MEAN[channel]) / IMAGENET_STD[channel]
+        }
+        Normalization::ImageNet => value / 255.0,
+    }
+}
+
+/// Converts tightly packed RGBA8 pixels into normalized `f32` values for ML frameworks.
+pub fn rgba8_to_f32(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    normalization: Normalization,
+    layout: TensorLayout,
+    order: ChannelOrder,
+) -> Result<Vec<f32>, TensorError> {
+    let pixel_count = (width as usize)
+        .checked_mul(height as usize)
+        .filter(|count| count.checked_mul(4) == Some(pixels.len()))
+        .ok_or(TensorError::SizeMismatch {
+            width,
+            height,
+            len: pixels.len(),
+        })?;
+
+    let channels = order.source_channels();
+    let mut out = vec![0.0; pixel_count * channels.len()];
+    for (i, pixel) in pixels.chunks_exact(4).enumerate() {
+        for (c, &source) in channels.iter().enumerate() {
+            let index = match layout {
+                TensorLayout::Hwc => i * channels.len() + c,
+                TensorLayout::Chw => c * pixel_count + i,
+            };
+            out[index] = normalize(pixel[source], source, normalization);
+        }
+    }
+
+    Ok(out)
+}
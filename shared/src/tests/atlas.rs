@@ -0,0 +1,138 @@
+use crate::atlas::{pack_atlas, AtlasError, AtlasRequest};
+
+#[test]
+fn packs_three_sizes_without_overlap() {
+    let requests = [
+        AtlasRequest {
+            id: "small",
+            width: 16,
+            height: 16,
+        },
+        AtlasRequest {
+            id: "wide",
+            width: 48,
+            height: 24,
+        },
+        AtlasRequest {
+            id: "tall",
+            width: 20,
+            height: 40,
+        },
+    ];
+
+    let atlas = pack_atlas(&requests, 64).unwrap();
+    assert_eq!(atlas.placements.len(), 3);
+
+    let rects: Vec<_> = requests.iter().map(|r| atlas.placements[r.id]).collect();
+    for (i, a) in rects.iter().enumerate() {
+        assert!(a.x + a.width <= atlas.width);
+        assert!(a.y + a.height <= atlas.height);
+        for b in &rects[i + 1..] {
+            assert!(!a.overlaps(b), "{a:?} overlaps {b:?}");
+        }
+    }
+
+    let uv = atlas.placements["small"].uv(atlas.width, atlas.height);
+    assert!(uv.iter().all(|v| (0.0..=1.0).contains(v)));
+}
+
+#[test]
+fn rejects_request_wider_than_atlas() {
+    let requests = [AtlasRequest {
+        id: 0,
+        width: 128,
+        height: 8,
+    }];
+
+    assert_eq!(
+        pack_atlas(&requests, 64).unwrap_err(),
+        AtlasError::TooWide {
+            index: 0,
+            width: 128,
+            atlas_width: 64
+        }
+    );
+}
+
+#[test]
+fn rejects_duplicate_ids() {
+    let requests = [
+        AtlasRequest {
+            id: "a",
+            width: 8,
+            height: 8,
+        },
+        AtlasRequest {
+            id: "a",
+            width: 4,
+            height: 4,
+        },
+    ];
+
+    assert_eq!(
+        pack_atlas(&requests, 64).unwrap_err(),
+        AtlasError::DuplicateId { index: 1 }
+    );
+}
+
+#[test]
+fn rejects_empty_request() {
+    let requests = [
+        AtlasRequest {
+            id: 0,
+            width: 8,
+            height: 8,
+        },
+        AtlasRequest {
+            id: 1,
+            width: 8,
+            height: 0,
+        },
+    ];
+
+    assert_eq!(
+        pack_atlas(&requests, 64).unwrap_err(),
+        AtlasError::EmptyRequest { index: 1 }
+    );
+}
+
+#[test]
+fn huge_requests_wrap_instead_of_overflowing() {
+    let requests = [
+        AtlasRequest {
+            id: 0,
+            width: 3_000_000_000,
+            height: 1,
+        },
+        AtlasRequest {
+            id: 1,
+            width: 3_000_000_000,
+            height: 1,
+        },
+    ];
+
+    let atlas = pack_atlas(&requests, u32::MAX).unwrap();
+    assert_eq!(atlas.height, 2);
+    assert!(!atlas.placements[&0].overlaps(&atlas.placements[&1]));
+}
+
+#[test]
+fn rejects_atlas_taller_than_u32() {
+    let requests = [
+        AtlasRequest {
+            id: 0,
+            width: 8,
+            height: u32::MAX,
+        },
+        AtlasRequest {
+            id: 1,
+            width: 8,
+            height: 1,
+        },
+    ];
+
+    assert_eq!(
+        pack_atlas(&requests, 8).unwrap_err(),
+        AtlasError::TooLarge { index: 1 }
+    );
+}
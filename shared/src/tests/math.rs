@@ -0,0 +1,256 @@
+use crate::math::{
+    compute_normals, morph, signed_area, triangulate_polygon, MorphError, NormalsError,
+    TriangulationError,
+};
+
+fn triangle(points: &[[f32; 2]], t: &[usize; 3]) -> [[f32; 2]; 3] {
+    [points[t[0]], points[t[1]], points[t[2]]]
+}
+
+/// Checks that the triangles are counter-clockwise and tile the polygon's area exactly.
+fn assert_valid_triangulation(points: &[[f32; 2]], triangles: &[[usize; 3]], expected: usize) {
+    assert_eq!(triangles.len(), expected);
+
+    let mut covered = 0.0;
+    for t in triangles {
+        let area = signed_area(&triangle(points, t));
+        assert!(area > 0.0, "triangle {t:?} is not counter-clockwise");
+        covered += area.abs();
+    }
+
+    let polygon = signed_area(points).abs();
+    assert!(
+        (covered - polygon).abs() <= polygon * 1e-4,
+        "triangles cover {covered}, polygon area is {polygon}"
+    );
+}
+
+/// Orientation-independent point-in-triangle test.
+fn covers(tri: [[f32; 2]; 3], p: [f32; 2]) -> bool {
+    let side =
+        |a: [f32; 2], b: [f32; 2]| (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]);
+    let (d0, d1, d2) = (
+        side(tri[0], tri[1]),
+        side(tri[1], tri[2]),
+        side(tri[2], tri[0]),
+    );
+    (d0 >= 0.0 && d1 >= 0.0 && d2 >= 0.0) || (d0 <= 0.0 && d1 <= 0.0 && d2 <= 0.0)
+}
+
+fn circle(vertices: usize) -> Vec<[f32; 2]> {
+    (0..vertices)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / vertices as f32;
+            [angle.cos(), angle.sin()]
+        })
+        .collect()
+}
+
+#[test]
+fn triangulates_convex_pentagon() {
+    let points = circle(5);
+    let triangles = triangulate_polygon(&points).unwrap();
+    assert_valid_triangulation(&points, &triangles, 3);
+}
+
+#[test]
+fn triangulates_concave_arrow_clockwise() {
+    // Arrow pointing right, wound clockwise.
+    let points = [
+        [0.0, 0.25],
+        [0.5, 0.25],
+        [0.5, 0.5],
+        [1.0, 0.0],
+        [0.5, -0.5],
+        [0.5, -0.25],
+        [0.0, -0.25],
+    ];
+    assert!(signed_area(&points) < 0.0);
+
+    let triangles = triangulate_polygon(&points).unwrap();
+    assert_valid_triangulation(&points, &triangles, 5);
+
+    // Inside the convex hull but outside the arrow, above the shaft and left of the head.
+    let notch = [0.4, 0.35];
+    for t in &triangles {
+        assert!(
+            !covers(triangle(&points, t), notch),
+            "triangle {t:?} covers the notch"
+        );
+    }
+}
+
+#[test]
+fn triangulates_small_polygons() {
+    let tiny_triangle = [[0.0, 0.0], [1e-3, 0.0], [0.0, 1e-3]];
+    let triangles = triangulate_polygon(&tiny_triangle).unwrap();
+    assert_valid_triangulation(&tiny_triangle, &triangles, 1);
+
+    let tiny_square = [[0.0, 0.0], [1e-3, 0.0], [1e-3, 1e-3], [0.0, 1e-3]];
+    let triangles = triangulate_polygon(&tiny_square).unwrap();
+    assert_valid_triangulation(&tiny_square, &triangles, 2);
+}
+
+#[test]
+fn keeps_every_corner_of_dense_circles() {
+    for vertices in [1000, 4000] {
+        let points = circle(vertices);
+        let triangles = triangulate_polygon(&points).unwrap();
+        assert_valid_triangulation(&points, &triangles, vertices - 2);
+    }
+}
+
+#[test]
+fn drops_collinear_vertex_on_edge() {
+    let points = [[0.0, 0.0], [0.5, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    let triangles = triangulate_polygon(&points).unwrap();
+    assert_valid_triangulation(&points, &triangles, 2);
+    assert!(triangles.iter().all(|t| !t.contains(&1)));
+}
+
+#[test]
+fn rejects_self_intersecting_polygons() {
+    assert_eq!(
+        triangulate_polygon(&[[0.0, 0.0], [3.0, 0.0], [3.0, 2.0], [1.0, -1.0], [0.0, 2.0]]),
+        Err(TriangulationError::NotSimple)
+    );
+    // A bowtie's lobes cancel out to zero area, but crossing edges are reported first.
+    assert_eq!(
+        triangulate_polygon(&[[0.0, 0.0], [1.0, 1.0], [1.0, 0.0], [0.0, 1.0]]),
+        Err(TriangulationError::NotSimple)
+    );
+}
+
+#[test]
+fn rejects_degenerate_polygons() {
+    assert_eq!(
+        triangulate_polygon(&[[0.0, 0.0], [1.0, 1.0]]),
+        Err(TriangulationError::TooFewPoints { count: 2 })
+    );
+    assert_eq!(
+        triangulate_polygon(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]),
+        Err(TriangulationError::Degenerate)
+    );
+}
+
+fn assert_close3(a: [f32; 3], b: [f32; 3]) {
+    assert!((0..3).all(|i| (a[i] - b[i]).abs() < 1e-5), "{a:?} != {b:?}");
+}
+
+fn normalized(v: [f32; 3]) -> [f32; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    v.map(|c| c / length)
+}
+
+// Two triangles folded along the shared edge 1-2.
+const FOLDED: [[f32; 3]; 4] = [
+    [0.0, 0.0, 0.0],
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [1.0, 1.0, 1.0],
+];
+const FOLDED_INDICES: [u32; 6] = [0, 1, 2, 1, 3, 2];
+
+#[test]
+fn computes_flat_normals_per_face() {
+    // Unshared copies of the folded mesh so every vertex belongs to one face.
+    let positions: Vec<[f32; 3]> = FOLDED_INDICES.iter().map(|&i| FOLDED[i as usize]).collect();
+    let indices: Vec<u32> = (0..6).collect();
+    let mut normals = vec![[0.0; 3]; 6];
+
+    compute_normals(&positions, &mut normals, &indices, false).unwrap();
+
+    let first = [0.0, 0.0, 1.0];
+    let second = normalized([-1.0, -1.0, 1.0]);
+    for (i, &normal) in normals.iter().enumerate() {
+        assert_close3(normal, if i < 3 { first } else { second });
+    }
+}
+
+#[test]
+fn averages_smooth_normals_at_shared_edge() {
+    let mut normals = [[0.0; 3]; 4];
+    compute_normals(&FOLDED, &mut normals, &FOLDED_INDICES, true).unwrap();
+
+    let first = [0.0, 0.0, 1.0];
+    let second = normalized([-1.0, -1.0, 1.0]);
+    let shared = normalized([
+        first[0] + second[0],
+        first[1] + second[1],
+        first[2] + second[2],
+    ]);
+    assert_close3(normals[0], first);
+    assert_close3(normals[1], shared);
+    assert_close3(normals[2], shared);
+    assert_close3(normals[3], second);
+}
+
+#[test]
+fn skips_zero_area_triangles() {
+    let mut normals = [[0.0; 3]; 4];
+    let indices = [0, 1, 2, 3, 3, 1];
+    compute_normals(&FOLDED, &mut normals, &indices, true).unwrap();
+
+    assert_close3(normals[1], [0.0, 0.0, 1.0]);
+    assert_eq!(normals[3], [0.0; 3]);
+}
+
+#[test]
+fn rejects_invalid_normal_inputs() {
+    let mut normals = [[0.0; 3]; 4];
+    assert_eq!(
+        compute_normals(&FOLDED, &mut normals[..3], &FOLDED_INDICES, true),
+        Err(NormalsError::LengthMismatch {
+            positions: 4,
+            normals: 3
+        })
+    );
+    assert_eq!(
+        compute_normals(&FOLDED, &mut normals, &[0, 1], true),
+        Err(NormalsError::IncompleteTriangle { indices: 2 })
+    );
+    assert_eq!(
+        compute_normals(&FOLDED, &mut normals, &[0, 1, 4], true),
+        Err(NormalsError::IndexOutOfBounds {
+            index: 4,
+            vertices: 4
+        })
+    );
+}
+
+#[test]
+fn morphs_positions_and_colors() {
+    let from_positions = [[-0.5, 0.5], [0.5, 0.5], [0.0, -0.5]];
+    let to_positions = [[-1.0, 1.0], [1.0, 0.0], [0.5, -1.0]];
+    let from_colors = [[1.0, 0.0, 0.0, 1.0]; 3];
+    let to_colors = [[0.0, 0.0, 1.0, 0.0]; 3];
+
+    assert_eq!(
+        morph(&from_positions, &to_positions, 0.0).unwrap(),
+        from_positions
+    );
+    assert_eq!(
+        morph(&from_positions, &to_positions, 1.0).unwrap(),
+        to_positions
+    );
+
+    let midpoint = morph(&from_positions, &to_positions, 0.5).unwrap();
+    for ((mid, a), b) in midpoint.iter().zip(&from_positions).zip(&to_positions) {
+        for i in 0..2 {
+            assert!((mid[i] - (a[i] + b[i]) / 2.0).abs() < 1e-6);
+        }
+    }
+
+    assert_eq!(
+        morph(&from_colors, &to_colors, 0.5).unwrap(),
+        [[0.5, 0.0, 0.5, 0.5]; 3]
+    );
+}
+
+#[test]
+fn rejects_morph_between_different_lengths() {
+    assert_eq!(
+        morph(&[[0.0, 0.0]; 3], &[[1.0, 1.0]; 4], 0.5),
+        Err(MorphError::LengthMismatch { from: 3, to: 4 })
+    );
+}
@@ -1,5 +1,11 @@
 use crate::entrypoint;
 
+mod atlas;
+mod math;
+mod plot;
+mod scene_graph;
+mod tensor;
+
 #[test]
 fn check_entrypoint_exists() {
     entrypoint();
@@ -0,0 +1,56 @@
+use std::f32::consts::TAU;
+
+use crate::plot::plot_samples;
+
+#[test]
+fn sine_crosses_center_at_half_period() {
+    let count = 100;
+    let samples: Vec<f32> = (0..count)
+        .map(|i| (i as f32 * TAU / count as f32).sin())
+        .collect();
+
+    let plot = plot_samples(&samples).unwrap();
+    assert_eq!(plot.strips.len(), 1);
+    let strip = &plot.strips[0];
+    assert_eq!(strip.len(), count);
+    assert_eq!(strip[0][0], -1.0);
+    assert_eq!(strip[count - 1][0], 1.0);
+
+    // sin(0) is the minimum-to-maximum midpoint, so the curve starts on the center line.
+    assert!(strip[0][1].abs() < 1e-5);
+
+    // The only other crossing is at sample 50 (half a period).
+    let step = 2.0 / (count - 1) as f32;
+    let crossings: Vec<f32> = strip
+        .windows(2)
+        .skip(1)
+        .filter(|w| (w[0][1] > 0.0) != (w[1][1] > 0.0))
+        .map(|w| w[0][0] - w[0][1] * (w[1][0] - w[0][0]) / (w[1][1] - w[0][1]))
+        .collect();
+    assert_eq!(crossings.len(), 1);
+    let expected = 50.0 * step - 1.0;
+    assert!(
+        (crossings[0] - expected).abs() < step,
+        "crossed at {}, expected {expected}",
+        crossings[0]
+    );
+
+    assert!(plot.axes.contains(&[[-1.0, 0.0], [1.0, 0.0]]));
+}
+
+#[test]
+fn nan_samples_break_the_line() {
+    let plot = plot_samples(&[0.0, 1.0, f32::NAN, 2.0, 3.0, f32::NAN, 4.0]).unwrap();
+
+    assert_eq!(plot.strips.len(), 2);
+    assert_eq!(plot.strips[0].len(), 2);
+    assert_eq!(plot.strips[1].len(), 2);
+    assert_eq!((plot.min, plot.max), (0.0, 4.0));
+    assert_eq!(plot.axes.len(), 2);
+}
+
+#[test]
+fn rejects_samples_without_finite_values() {
+    assert!(plot_samples(&[]).is_none());
+    assert!(plot_samples(&[f32::NAN, f32::INFINITY]).is_none());
+}
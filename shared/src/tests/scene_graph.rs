@@ -0,0 +1,39 @@
+use std::f32::consts::FRAC_PI_2;
+
+use crate::scene_graph::{SceneNode, Transform2D};
+
+fn assert_close(a: [f32; 2], b: [f32; 2]) {
+    assert!(
+        (a[0] - b[0]).abs() < 1e-5 && (a[1] - b[1]).abs() < 1e-5,
+        "{a:?} != {b:?}"
+    );
+}
+
+#[test]
+fn child_inherits_parent_transform() {
+    let local = vec![[1.0, 0.0], [0.0, 1.0]];
+    let root = SceneNode::new(Transform2D::translation(2.0, 3.0))
+        .with_geometry(vec![[0.0, 0.0]])
+        .with_child(SceneNode::new(Transform2D::rotation(FRAC_PI_2)).with_geometry(local.clone()));
+
+    let world = root.flatten();
+    assert_eq!(world.len(), 3);
+    assert_close(world[0], [2.0, 3.0]);
+
+    // Rotate first (child), then translate (parent).
+    let composed = Transform2D::translation(2.0, 3.0) * Transform2D::rotation(FRAC_PI_2);
+    assert_close(world[1], composed.apply(local[0]));
+    assert_close(world[2], composed.apply(local[1]));
+    assert_close(world[1], [2.0, 4.0]);
+    assert_close(world[2], [1.0, 3.0]);
+}
+
+#[test]
+fn composition_order_matters() {
+    let translate = Transform2D::translation(1.0, 0.0);
+    let rotate = Transform2D::rotation(FRAC_PI_2);
+
+    // `translate * rotate` rotates first, then translates.
+    assert_close((translate * rotate).apply([1.0, 0.0]), [1.0, 1.0]);
+    assert_close((rotate * translate).apply([1.0, 0.0]), [0.0, 2.0]);
+}
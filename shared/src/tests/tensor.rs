@@ -0,0 +1,85 @@
+use crate::tensor::{rgba8_to_f32, ChannelOrder, Normalization, TensorError, TensorLayout};
+
+// A 2x1 image: opaque red, then half-transparent white.
+const PIXELS: [u8; 8] = [255, 0, 0, 255, 255, 255, 255, 128];
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn converts_to_interleaved_zero_to_one() {
+    let out = rgba8_to_f32(
+        &PIXELS,
+        2,
+        1,
+        Normalization::ZeroToOne,
+        TensorLayout::Hwc,
+        ChannelOrder::Rgba,
+    )
+    .unwrap();
+    assert_close(&out, &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 128.0 / 255.0]);
+}
+
+#[test]
+fn converts_to_planar_bgr_minus_one_to_one() {
+    let out = rgba8_to_f32(
+        &PIXELS,
+        2,
+        1,
+        Normalization::MinusOneToOne,
+        TensorLayout::Chw,
+        ChannelOrder::Bgr,
+    )
+    .unwrap();
+    // B plane, G plane, R plane; alpha is dropped.
+    assert_close(&out, &[-1.0, 1.0, -1.0, 1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn converts_with_imagenet_statistics() {
+    let out = rgba8_to_f32(
+        &PIXELS,
+        2,
+        1,
+        Normalization::ImageNet,
+        TensorLayout::Chw,
+        ChannelOrder::Rgb,
+    )
+    .unwrap();
+    let mean = [0.485, 0.456, 0.406];
+    let std = [0.229, 0.224, 0.225];
+    assert_close(
+        &out,
+        &[
+            (1.0 - mean[0]) / std[0],
+            (1.0 - mean[0]) / std[0],
+            (0.0 - mean[1]) / std[1],
+            (1.0 - mean[1]) / std[1],
+            (0.0 - mean[2]) / std[2],
+            (1.0 - mean[2]) / std[2],
+        ],
+    );
+}
+
+#[test]
+fn rejects_mismatched_buffer() {
+    assert_eq!(
+        rgba8_to_f32(
+            &PIXELS,
+            2,
+            2,
+            Normalization::ZeroToOne,
+            TensorLayout::Hwc,
+            ChannelOrder::Rgba,
+        ),
+        Err(TensorError::SizeMismatch {
+            width: 2,
+            height: 2,
+            len: 8
+        })
+    );
+}